@@ -1,8 +1,17 @@
 use std::process::Command;
 use std::env;
 use std::fs;
-use std::time::Duration;
-use std::path::Path;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use std::path::{Path, PathBuf};
+use sysinfo::{CpuExt, CpuRefreshKind, RefreshKind, System, SystemExt};
+
+// The default module order, used when no config file is present or a config
+// doesn't specify an [order] section.
+const DEFAULT_MODULES: &[&str] = &[
+    "os", "kernel", "uptime", "shell", "terminal", "packages", "cpu", "gpu", "memory", "battery",
+    "now_playing",
+];
 
 // Struct to hold system information
 struct SystemInfo {
@@ -14,73 +23,214 @@ struct SystemInfo {
     terminal: Option<String>,
     packages: String,
     cpu: String,
-    gpu: String,
-    gpu_driver: String,
+    gpu: Vec<String>,
+    gpu_driver: Vec<String>,
+    gpu_stats: Option<GpuStats>,
     memory: (String, String),  // Used / Total
+    battery: Option<String>,
+    now_playing: Option<String>,
+    // Per-module timings in microseconds, populated only when run with --stat
+    timings: Option<HashMap<String, u128>>,
+}
+
+// User-tunable settings loaded from $XDG_CONFIG_HOME/fumorfetch/config
+struct Config {
+    modules: Vec<String>,
+    header_color: String,
+    label_color: String,
+    logo_path: Option<String>,
+}
+
+impl Config {
+    fn default() -> Config {
+        Config {
+            modules: DEFAULT_MODULES.iter().map(|s| s.to_string()).collect(),
+            header_color: String::from("\x1b[1;36m"),
+            label_color: String::from("\x1b[1;32m"),
+            logo_path: None,
+        }
+    }
+
+    fn load() -> Config {
+        match fs::read_to_string(config_path()) {
+            Ok(contents) => parse_config(&contents),
+            Err(_) => Config::default(),
+        }
+    }
+}
+
+fn config_path() -> PathBuf {
+    let config_home = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            let home = env::var("HOME").unwrap_or_else(|_| String::from("."));
+            Path::new(&home).join(".config")
+        });
+
+    config_home.join("fumorfetch").join("config")
+}
+
+// Parses a small INI-style config:
+//
+//   [modules]
+//   packages = false
+//
+//   [order]
+//   order = os,kernel,uptime,cpu,memory
+//
+//   [colors]
+//   label = \x1b[1;35m
+//
+//   [logo]
+//   path = /home/user/.config/fumorfetch/logo.txt
+fn parse_config(contents: &str) -> Config {
+    let mut config = Config::default();
+    let mut disabled = Vec::new();
+    let mut section = String::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..line.len() - 1].trim().to_lowercase();
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_lowercase();
+        let value = value.trim();
+
+        match section.as_str() {
+            "modules" if value.eq_ignore_ascii_case("false") => {
+                disabled.push(key);
+            }
+            "order" if key == "order" => {
+                config.modules = value.split(',').map(|m| m.trim().to_string()).collect();
+            }
+            "colors" => match key.as_str() {
+                "header" => config.header_color = unescape_ansi(value),
+                "label" => config.label_color = unescape_ansi(value),
+                _ => {}
+            },
+            "logo" if key == "path" => {
+                config.logo_path = Some(value.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    config.modules.retain(|m| !disabled.contains(m));
+    config
+}
+
+// Config files can't embed a raw ESC byte comfortably, so colors are written as
+// the literal two characters `\x1b` followed by the rest of the escape code.
+fn unescape_ansi(value: &str) -> String {
+    value.replace("\\x1b", "\x1b")
+}
+
+// Runtime GPU telemetry (utilization, temperature, VRAM usage)
+struct GpuStats {
+    load_percent: u32,
+    temp_celsius: u32,
+    vram_used: String,
+    vram_total: String,
 }
 
 fn main() {
+    let stat_mode = env::args().any(|arg| arg == "--stat");
+    let config = Config::load();
+
     // Get system information
-    let info = get_system_info();
-    
+    let info = get_system_info(stat_mode);
+
     // Display the system information
-    display_info(&info);
+    display_info(&info, &config);
 }
 
-fn get_system_info() -> SystemInfo {
+fn get_system_info(stat_mode: bool) -> SystemInfo {
+    let mut timings = HashMap::new();
+
+    // sysinfo backs the portable core fields (hostname/OS/kernel/uptime/CPU/memory)
+    // so these numbers are correct on Linux, macOS, and Windows alike
+    let mut sys = System::new_with_specifics(
+        RefreshKind::new()
+            .with_memory()
+            .with_cpu(CpuRefreshKind::everything()),
+    );
+    sys.refresh_all();
+
+    let hostname = timed(stat_mode, "hostname", &mut timings, || get_hostname(&sys));
+    let os = timed(stat_mode, "os", &mut timings, || get_os_info(&sys));
+    let kernel = timed(stat_mode, "kernel", &mut timings, || get_kernel_version(&sys));
+    let uptime = timed(stat_mode, "uptime", &mut timings, || get_uptime(&sys));
+    let shell = timed(stat_mode, "shell", &mut timings, get_shell);
+    let terminal = timed(stat_mode, "terminal", &mut timings, get_terminal);
+    let packages = timed(stat_mode, "packages", &mut timings, get_package_count);
+    let cpu = timed(stat_mode, "cpu", &mut timings, || get_cpu_info(&sys));
+    let (gpu, gpu_driver) = timed(stat_mode, "gpu", &mut timings, get_gpu_info);
+    let gpu_stats = timed(stat_mode, "gpu", &mut timings, get_gpu_stats);
+    let memory = timed(stat_mode, "memory", &mut timings, || get_memory_info(&sys));
+    let battery = timed(stat_mode, "battery", &mut timings, get_battery_info);
+    let now_playing = timed(stat_mode, "now_playing", &mut timings, get_now_playing);
+
     SystemInfo {
-        hostname: get_hostname(),
-        os: get_os_info(),
-        kernel: get_kernel_version(),
-        uptime: get_uptime(),
-        shell: get_shell(),
-        terminal: get_terminal(),
-        packages: get_package_count(),
-        cpu: get_cpu_info(),
-        gpu: get_gpu_info().0,
-        gpu_driver: get_gpu_info().1,
-        memory: get_memory_info(),
-    }
-}
-
-fn get_hostname() -> String {
-    fs::read_to_string("/etc/hostname")
-        .unwrap_or_else(|_| String::from("Unknown"))
-        .trim()
-        .to_string()
+        hostname,
+        os,
+        kernel,
+        uptime,
+        shell,
+        terminal,
+        packages,
+        cpu,
+        gpu,
+        gpu_driver,
+        gpu_stats,
+        memory,
+        battery,
+        now_playing,
+        timings: stat_mode.then_some(timings),
+    }
 }
 
-fn get_os_info() -> String {
-    if let Ok(os_release) = fs::read_to_string("/etc/os-release") {
-        for line in os_release.lines() {
-            if line.starts_with("PRETTY_NAME=") {
-                return line.replacen("PRETTY_NAME=", "", 1)
-                    .trim_matches('"')
-                    .to_string();
-            }
-        }
+// Runs `f`, and when `stat_mode` is on, records its elapsed time (in
+// microseconds) under `name` so `--stat` can show it beside the module's line.
+// Timings accumulate per name, so multiple getters feeding the same displayed
+// module (e.g. "gpu": get_gpu_info() + get_gpu_stats()) report a combined total.
+fn timed<T>(stat_mode: bool, name: &str, timings: &mut HashMap<String, u128>, f: impl FnOnce() -> T) -> T {
+    if !stat_mode {
+        return f();
     }
-    String::from("Linux")
+
+    let start = Instant::now();
+    let result = f();
+    *timings.entry(name.to_string()).or_insert(0) += start.elapsed().as_micros();
+    result
 }
 
-fn get_kernel_version() -> String {
-    let output = Command::new("uname")
-        .arg("-r")
-        .output()
-        .unwrap_or_else(|_| panic!("Failed to get kernel version"));
-    
-    String::from_utf8_lossy(&output.stdout).trim().to_string()
+fn get_hostname(sys: &System) -> String {
+    sys.host_name().unwrap_or_else(|| String::from("Unknown"))
 }
 
-fn get_uptime() -> String {
-    if let Ok(uptime_str) = fs::read_to_string("/proc/uptime") {
-        if let Some(secs_str) = uptime_str.split_whitespace().next() {
-            if let Ok(secs) = secs_str.parse::<f64>() {
-                return format_uptime(Duration::from_secs_f64(secs));
-            }
-        }
+fn get_os_info(sys: &System) -> String {
+    let name = sys.name().unwrap_or_else(|| String::from("Linux"));
+    match sys.os_version() {
+        Some(version) => format!("{} {}", name, version),
+        None => name,
     }
-    String::from("Unknown")
+}
+
+fn get_kernel_version(sys: &System) -> String {
+    sys.kernel_version().unwrap_or_else(|| String::from("Unknown"))
+}
+
+fn get_uptime(sys: &System) -> String {
+    format_uptime(Duration::from_secs(sys.uptime()))
 }
 
 fn format_uptime(duration: Duration) -> String {
@@ -136,48 +286,21 @@ fn get_package_count() -> String {
     String::from("Unknown")
 }
 
-fn get_cpu_info() -> String {
-    if let Ok(cpu_info) = fs::read_to_string("/proc/cpuinfo") {
-        for line in cpu_info.lines() {
-            if line.starts_with("model name") {
-                return line.split(':')
-                    .nth(1)
-                    .unwrap_or("Unknown")
-                    .trim()
-                    .to_string();
-            }
-        }
+fn get_cpu_info(sys: &System) -> String {
+    let brand = sys.global_cpu_info().brand().trim();
+    if brand.is_empty() {
+        String::from("Unknown CPU")
+    } else {
+        brand.to_string()
     }
-    String::from("Unknown CPU")
 }
 
-fn get_memory_info() -> (String, String) {
-    let mut total = 0;
-    let mut available = 0;
-    
-    if let Ok(meminfo) = fs::read_to_string("/proc/meminfo") {
-        for line in meminfo.lines() {
-            if line.starts_with("MemTotal:") {
-                if let Some(value) = line.split_whitespace().nth(1) {
-                    if let Ok(kbytes) = value.parse::<u64>() {
-                        total = kbytes;
-                    }
-                }
-            } else if line.starts_with("MemAvailable:") {
-                if let Some(value) = line.split_whitespace().nth(1) {
-                    if let Ok(kbytes) = value.parse::<u64>() {
-                        available = kbytes;
-                    }
-                }
-            }
-        }
-    }
-    
-    // Convert to human-readable format (MB or GB)
-    let used = total - available;
-    let used_str = format_memory_size(used);
-    let total_str = format_memory_size(total);
-    
+fn get_memory_info(sys: &System) -> (String, String) {
+    // sysinfo reports bytes; format_memory_size works in KB like the rest of
+    // the codebase (see the GPU VRAM stats below), so convert down once here
+    let used_str = format_memory_size(sys.used_memory() / 1024);
+    let total_str = format_memory_size(sys.total_memory() / 1024);
+
     (used_str, total_str)
 }
 
@@ -192,21 +315,138 @@ fn format_memory_size(size_kb: u64) -> String {
     }
 }
 
-fn get_gpu_info() -> (String, String) {
+// Reads the currently playing track from whichever MPRIS-compatible player is
+// on the session bus. Returns None (and the module is simply omitted) when no
+// player is running or D-Bus is unreachable, e.g. on headless systems, so the
+// feature is gated behind "mpris" since it pulls in zbus.
+#[cfg(feature = "mpris")]
+fn get_now_playing() -> Option<String> {
+    use std::collections::HashMap as StdHashMap;
+    use zbus::blocking::{Connection, Proxy};
+    use zbus::zvariant::{Array, OwnedValue};
+
+    let connection = Connection::session().ok()?;
+
+    let dbus_proxy = Proxy::new(
+        &connection,
+        "org.freedesktop.DBus",
+        "/org/freedesktop/DBus",
+        "org.freedesktop.DBus",
+    )
+    .ok()?;
+
+    let names: Vec<String> = dbus_proxy.call("ListNames", &()).ok()?;
+    let player_name = names.into_iter().find(|n| n.starts_with("org.mpris.MediaPlayer2."))?;
+
+    let player_proxy = Proxy::new(
+        &connection,
+        player_name.as_str(),
+        "/org/mpris/MediaPlayer2",
+        "org.mpris.MediaPlayer2.Player",
+    )
+    .ok()?;
+
+    let status: String = player_proxy.get_property("PlaybackStatus").ok()?;
+    let metadata: StdHashMap<String, OwnedValue> = player_proxy.get_property("Metadata").ok()?;
+
+    let title = metadata
+        .get("xesam:title")
+        .and_then(|v| <&str>::try_from(v).ok())
+        .unwrap_or("Unknown");
+
+    let artist = metadata
+        .get("xesam:artist")
+        .and_then(|v| <&Array>::try_from(v).ok())
+        .map(|artists| {
+            artists
+                .iter()
+                .filter_map(|a| <&str>::try_from(a).ok())
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_else(|| String::from("Unknown"));
+
+    Some(format!("{} – {} ({})", artist, title, status))
+}
+
+#[cfg(not(feature = "mpris"))]
+fn get_now_playing() -> Option<String> {
+    None
+}
+
+// Reads /sys/class/power_supply/BAT*/, aggregating multiple batteries when
+// present. Returns None on desktops and other systems with no battery.
+fn get_battery_info() -> Option<String> {
+    let power_supply_dir = fs::read_dir("/sys/class/power_supply").ok()?;
+
+    let mut total_capacity = 0u32;
+    let mut battery_count = 0u32;
+    let mut status = String::from("Unknown");
+
+    for entry in power_supply_dir.flatten() {
+        let name = entry.file_name();
+        if !name.to_string_lossy().starts_with("BAT") {
+            continue;
+        }
+
+        let battery_dir = entry.path();
+
+        let capacity = fs::read_to_string(battery_dir.join("capacity"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u32>().ok());
+
+        if let Some(capacity) = capacity {
+            total_capacity += capacity;
+            battery_count += 1;
+        }
+
+        if let Ok(battery_status) = fs::read_to_string(battery_dir.join("status")) {
+            status = battery_status.trim().to_string();
+        }
+    }
+
+    if battery_count == 0 {
+        return None;
+    }
+
+    Some(format!("{}% ({})", total_capacity / battery_count, status))
+}
+
+fn get_gpu_info() -> (Vec<String>, Vec<String>) {
+    // Prefer Vulkan enumeration: it reports every adapter (useful for hybrid
+    // graphics laptops) and gives proper marketing names instead of lspci's
+    // often-truncated codenames. When NVML is also available, let it refine
+    // the NVIDIA entry Vulkan already found with a more precise name/driver
+    // version, rather than short-circuiting and hiding the other adapters.
+    #[cfg(feature = "vulkan")]
+    if let Some((names, drivers, vendor_ids)) = get_gpu_info_vulkan() {
+        return apply_nvml_naming(names, drivers, vendor_ids);
+    }
+
+    // Prefer NVML for precise NVIDIA model names and driver versions, when
+    // Vulkan isn't available at all.
+    #[cfg(feature = "nvml")]
+    if let Some((name, driver)) = get_nvidia_info_nvml() {
+        return (vec![name], vec![driver]);
+    }
+
     // Try multiple methods to detect GPU
-    
-    // Try lspci first (most universal)
+
+    // Try lspci next (most universal, and handles multi-GPU systems too)
     if let Ok(output) = Command::new("lspci").output() {
         let lspci_output = String::from_utf8_lossy(&output.stdout);
-        
+
+        let mut names = Vec::new();
+        let mut drivers = Vec::new();
+
         // Look for graphics cards in lspci output
         for line in lspci_output.lines() {
             let line_lower = line.to_lowercase();
-            if line_lower.contains("vga") || 
-               line_lower.contains("display") || 
+            if line_lower.contains("vga") ||
+               line_lower.contains("display") ||
                line_lower.contains("3d") ||
                line_lower.contains("graphics") {
-                
+
                 // Extract the GPU model from the line
                 if let Some(gpu_model) = line.split(':').nth(2) {
                     // Try to detect if it's NVIDIA, AMD, or Intel
@@ -220,22 +460,27 @@ fn get_gpu_info() -> (String, String) {
                     } else {
                         String::from("Unknown")
                     };
-                    
-                    return (gpu_name.to_string(), driver_version);
+
+                    names.push(gpu_name.to_string());
+                    drivers.push(driver_version);
                 }
             }
         }
+
+        if !names.is_empty() {
+            return (names, drivers);
+        }
     }
-    
+
     // Fallback to other methods if lspci didn't work
     // Check for NVIDIA GPU with nvidia-smi
     if let Ok(output) = Command::new("nvidia-smi").args(&["--query-gpu=name", "--format=csv,noheader"]).output() {
         if !output.stdout.is_empty() {
             let gpu_name = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            return (gpu_name, get_nvidia_driver_version());
+            return (vec![gpu_name], vec![get_nvidia_driver_version()]);
         }
     }
-    
+
     // Check for AMD GPU with lshw
     if let Ok(output) = Command::new("lshw").args(&["-C", "display"]).output() {
         let lshw_output = String::from_utf8_lossy(&output.stdout);
@@ -243,13 +488,225 @@ fn get_gpu_info() -> (String, String) {
             if line.contains("product:") {
                 if let Some(product) = line.split(':').nth(1) {
                     let gpu_name = product.trim();
-                    return (gpu_name.to_string(), get_amd_driver_version());
+                    return (vec![gpu_name.to_string()], vec![get_amd_driver_version()]);
                 }
             }
         }
     }
-    
-    (String::from("Unknown GPU"), String::from("Unknown"))
+
+    (vec![String::from("Unknown GPU")], vec![String::from("Unknown")])
+}
+
+// Enumerates every physical device via the Vulkan loader, which reports real
+// marketing names (VkPhysicalDeviceProperties.deviceName) instead of the
+// codenames lspci sometimes shows. Returns None when no Vulkan loader/ICD is
+// available, so callers fall back to the lspci-based path. The PCI vendor ID
+// is returned alongside each device so callers can identify which entry is
+// the NVIDIA one (e.g. to refine it with NVML) without guessing from the name.
+#[cfg(feature = "vulkan")]
+fn get_gpu_info_vulkan() -> Option<(Vec<String>, Vec<String>, Vec<u32>)> {
+    use ash::vk;
+    use std::ffi::CStr;
+
+    let entry = unsafe { ash::Entry::load() }.ok()?;
+    let app_info = vk::ApplicationInfo::builder();
+    let create_info = vk::InstanceCreateInfo::builder().application_info(&app_info);
+    let instance = unsafe { entry.create_instance(&create_info, None) }.ok()?;
+
+    let physical_devices = unsafe { instance.enumerate_physical_devices() }.ok();
+
+    let result = physical_devices.and_then(|physical_devices| {
+        if physical_devices.is_empty() {
+            return None;
+        }
+
+        let mut names = Vec::new();
+        let mut drivers = Vec::new();
+        let mut vendor_ids = Vec::new();
+
+        for device in physical_devices {
+            let properties = unsafe { instance.get_physical_device_properties(device) };
+            let name = unsafe { CStr::from_ptr(properties.device_name.as_ptr()) }
+                .to_string_lossy()
+                .to_string();
+
+            // Map the PCI vendor ID to the right driver-version helper
+            let driver_version = match properties.vendor_id {
+                0x10DE => get_nvidia_driver_version(),
+                0x1002 => get_amd_driver_version(),
+                0x8086 => get_intel_driver_version(),
+                _ => String::from("Unknown"),
+            };
+
+            names.push(name);
+            drivers.push(driver_version);
+            vendor_ids.push(properties.vendor_id);
+        }
+
+        Some((names, drivers, vendor_ids))
+    });
+
+    unsafe { instance.destroy_instance(None) };
+
+    result
+}
+
+// Overlays NVML's more precise name/driver-version string onto the
+// Vulkan-enumerated entry whose PCI vendor ID matches NVIDIA (0x10DE),
+// leaving every other adapter (e.g. an integrated GPU on a hybrid-graphics
+// laptop) untouched.
+#[cfg(all(feature = "vulkan", feature = "nvml"))]
+fn apply_nvml_naming(mut names: Vec<String>, mut drivers: Vec<String>, vendor_ids: Vec<u32>) -> (Vec<String>, Vec<String>) {
+    if let Some((nvml_name, nvml_driver)) = get_nvidia_info_nvml() {
+        if let Some(idx) = vendor_ids.iter().position(|&vendor_id| vendor_id == 0x10DE) {
+            names[idx] = nvml_name;
+            drivers[idx] = nvml_driver;
+        }
+    }
+    (names, drivers)
+}
+
+#[cfg(all(feature = "vulkan", not(feature = "nvml")))]
+fn apply_nvml_naming(names: Vec<String>, drivers: Vec<String>, _vendor_ids: Vec<u32>) -> (Vec<String>, Vec<String>) {
+    (names, drivers)
+}
+
+fn get_gpu_stats() -> Option<GpuStats> {
+    // Prefer NVML for NVIDIA cards when the feature is compiled in
+    #[cfg(feature = "nvml")]
+    if let Some(stats) = get_gpu_stats_nvml() {
+        return Some(stats);
+    }
+
+    // Otherwise try sysfs (AMD/Intel expose these under /sys/class/drm)
+    if let Some(stats) = get_gpu_stats_sysfs() {
+        return Some(stats);
+    }
+
+    // Fall back to nvidia-smi for NVIDIA cards
+    get_gpu_stats_nvidia_smi()
+}
+
+fn get_gpu_stats_sysfs() -> Option<GpuStats> {
+    let drm_dir = fs::read_dir("/sys/class/drm").ok()?;
+
+    for entry in drm_dir.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with("card") || name.contains('-') {
+            continue;
+        }
+
+        let device_dir = entry.path().join("device");
+
+        let load_percent = fs::read_to_string(device_dir.join("gpu_busy_percent"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u32>().ok());
+
+        let vram_total_bytes = fs::read_to_string(device_dir.join("mem_info_vram_total"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok());
+
+        let vram_used_bytes = fs::read_to_string(device_dir.join("mem_info_vram_used"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok());
+
+        let temp_celsius = find_hwmon_temp(&device_dir.join("hwmon"));
+
+        if let (Some(load_percent), Some(vram_total_bytes), Some(vram_used_bytes), Some(temp_celsius)) =
+            (load_percent, vram_total_bytes, vram_used_bytes, temp_celsius)
+        {
+            return Some(GpuStats {
+                load_percent,
+                temp_celsius,
+                vram_used: format_memory_size(vram_used_bytes / 1024),
+                vram_total: format_memory_size(vram_total_bytes / 1024),
+            });
+        }
+    }
+
+    None
+}
+
+fn find_hwmon_temp(hwmon_dir: &Path) -> Option<u32> {
+    let entries = fs::read_dir(hwmon_dir).ok()?;
+
+    for entry in entries.flatten() {
+        let millidegrees = fs::read_to_string(entry.path().join("temp1_input"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u32>().ok());
+
+        if let Some(millidegrees) = millidegrees {
+            return Some(millidegrees / 1000);
+        }
+    }
+
+    None
+}
+
+fn get_gpu_stats_nvidia_smi() -> Option<GpuStats> {
+    let output = Command::new("nvidia-smi")
+        .args(&[
+            "--query-gpu=utilization.gpu,temperature.gpu,memory.used,memory.total",
+            "--format=csv,noheader,nounits",
+        ])
+        .output()
+        .ok()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout.lines().next()?;
+    let mut fields = line.split(',').map(|s| s.trim());
+
+    let load_percent = fields.next()?.parse::<u32>().ok()?;
+    let temp_celsius = fields.next()?.parse::<u32>().ok()?;
+    let vram_used_mb = fields.next()?.parse::<u64>().ok()?;
+    let vram_total_mb = fields.next()?.parse::<u64>().ok()?;
+
+    Some(GpuStats {
+        load_percent,
+        temp_celsius,
+        vram_used: format_memory_size(vram_used_mb * 1024),
+        vram_total: format_memory_size(vram_total_mb * 1024),
+    })
+}
+
+// Query NVML directly instead of shelling out, giving exact model names and live
+// telemetry. Returns None when no NVIDIA device is present or the driver/library
+// isn't loaded (e.g. headless or Optimus systems without the proprietary driver active),
+// in which case the caller falls back to the lspci/nvidia-smi path.
+#[cfg(feature = "nvml")]
+fn get_nvidia_info_nvml() -> Option<(String, String)> {
+    use nvml_wrapper::Nvml;
+
+    let nvml = Nvml::init().ok()?;
+    let device = nvml.device_by_index(0).ok()?;
+
+    let name = device.name().ok()?;
+    let driver_version = nvml.sys_driver_version().ok()?;
+
+    Some((name, driver_version))
+}
+
+// Live utilization/temperature/VRAM via NVML, used as the preferred source in
+// get_gpu_stats() before falling back to sysfs or nvidia-smi.
+#[cfg(feature = "nvml")]
+fn get_gpu_stats_nvml() -> Option<GpuStats> {
+    use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+    use nvml_wrapper::Nvml;
+
+    let nvml = Nvml::init().ok()?;
+    let device = nvml.device_by_index(0).ok()?;
+
+    let utilization = device.utilization_rates().ok()?;
+    let temp_celsius = device.temperature(TemperatureSensor::Gpu).ok()?;
+    let memory_info = device.memory_info().ok()?;
+
+    Some(GpuStats {
+        load_percent: utilization.gpu,
+        temp_celsius,
+        vram_used: format_memory_size(memory_info.used / 1024),
+        vram_total: format_memory_size(memory_info.total / 1024),
+    })
 }
 
 fn get_nvidia_driver_version() -> String {
@@ -359,9 +816,56 @@ fn get_intel_driver_version() -> String {
     String::from("Unknown")
 }
 
-fn display_info(info: &SystemInfo) {
+// Renders the output line(s) for a single module key, honoring the config's
+// label color. Unknown module names (e.g. a typo in the user's config) render
+// nothing rather than erroring.
+fn module_lines(module: &str, info: &SystemInfo, config: &Config) -> Vec<String> {
+    let label = &config.label_color;
+
+    match module {
+        "os" => vec![format!("{}OS:\x1b[0m {}", label, info.os)],
+        "kernel" => vec![format!("{}Kernel:\x1b[0m {}", label, info.kernel)],
+        "uptime" => vec![format!("{}Uptime:\x1b[0m {}", label, info.uptime)],
+        "shell" => vec![format!("{}Shell:\x1b[0m {}", label, info.shell)],
+        "terminal" => vec![format!(
+            "{}Terminal:\x1b[0m {}",
+            label,
+            info.terminal.as_deref().unwrap_or("Unknown")
+        )],
+        "packages" => vec![format!("{}Packages:\x1b[0m {}", label, info.packages)],
+        "cpu" => vec![format!("{}CPU:\x1b[0m {}", label, info.cpu)],
+        "gpu" => {
+            let mut lines = Vec::new();
+
+            for (gpu, gpu_driver) in info.gpu.iter().zip(info.gpu_driver.iter()) {
+                lines.push(format!("{}GPU:\x1b[0m {}", label, gpu));
+                lines.push(format!("{}GPU Driver:\x1b[0m {}", label, gpu_driver));
+            }
+
+            if let Some(stats) = &info.gpu_stats {
+                lines.push(format!("{}GPU Load:\x1b[0m {}%", label, stats.load_percent));
+                lines.push(format!("{}GPU Temp:\x1b[0m {}°C", label, stats.temp_celsius));
+                lines.push(format!("{}VRAM:\x1b[0m {} / {}", label, stats.vram_used, stats.vram_total));
+            }
+
+            lines
+        }
+        "memory" => vec![format!("{}Memory:\x1b[0m {} / {}", label, info.memory.0, info.memory.1)],
+        "battery" => match &info.battery {
+            Some(battery) => vec![format!("{}Battery:\x1b[0m {}", label, battery)],
+            None => Vec::new(),
+        },
+        "now_playing" => match &info.now_playing {
+            Some(now_playing) => vec![format!("{}Now Playing:\x1b[0m {}", label, now_playing)],
+            None => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn display_info(info: &SystemInfo, config: &Config) {
     // Read the fumofetch ASCII art from file
-    let logo = read_logo_file().unwrap_or_else(|_| {
+    let logo = read_logo_file(config.logo_path.as_deref()).unwrap_or_else(|_| {
         // Fallback logo
         vec![
             "      /\\      ",
@@ -374,22 +878,27 @@ fn display_info(info: &SystemInfo) {
             "             ",
         ].iter().map(|s| s.to_string()).collect()
     });
-    
-    // Prepare the information lines with proper formatting
-    let info_lines = [
-        format!("\x1b[1;36m{}@{}\x1b[0m", whoami(), info.hostname),
-        format!("\x1b[1;32mOS:\x1b[0m {}", info.os),
-        format!("\x1b[1;32mKernel:\x1b[0m {}", info.kernel),
-        format!("\x1b[1;32mUptime:\x1b[0m {}", info.uptime),
-        format!("\x1b[1;32mShell:\x1b[0m {}", info.shell),
-        format!("\x1b[1;32mTerminal:\x1b[0m {}", info.terminal.as_deref().unwrap_or("Unknown")),
-        format!("\x1b[1;32mPackages:\x1b[0m {}", info.packages),
-        format!("\x1b[1;32mCPU:\x1b[0m {}", info.cpu),
-        format!("\x1b[1;32mGPU:\x1b[0m {}", info.gpu),
-        format!("\x1b[1;32mGPU Driver:\x1b[0m {}", info.gpu_driver),
-        format!("\x1b[1;32mMemory:\x1b[0m {} / {}", info.memory.0, info.memory.1),
+
+    // Prepare the information lines with proper formatting, in the order and
+    // selection the config specifies
+    let mut info_lines = vec![
+        format!("{}{}@{}\x1b[0m", config.header_color, whoami(), info.hostname),
     ];
-    
+
+    for module in &config.modules {
+        let mut lines = module_lines(module, info, config);
+
+        // When running with --stat, append the elapsed time of the getter
+        // beside its first output line
+        if let (Some(timings), Some(first_line)) = (&info.timings, lines.first_mut()) {
+            if let Some(micros) = timings.get(module) {
+                first_line.push_str(&format!(" \x1b[2m({}µs)\x1b[0m", micros));
+            }
+        }
+
+        info_lines.extend(lines);
+    }
+
     // Print escape sequence to hide cursor and ensure proper display
     print!("\x1b[?25l");
     
@@ -452,17 +961,21 @@ fn whoami() -> String {
         })
 }
 
-fn read_logo_file() -> Result<Vec<String>, std::io::Error> {
-    // Try to load from resources directory first
-    let resource_path = Path::new("resources").join("fumofetch_logo.txt");
-    
-    // If resource path exists, try to read it
-    let logo_content = if resource_path.exists() {
-        fs::read_to_string(resource_path)?
+fn read_logo_file(custom_path: Option<&str>) -> Result<Vec<String>, std::io::Error> {
+    // A logo path from the config file takes priority over the built-in lookup
+    let logo_content = if let Some(custom_path) = custom_path {
+        fs::read_to_string(custom_path)?
     } else {
-        // Fallback to checking in current directory
-        let logo_path = Path::new("fumofetch_logo.txt");
-        fs::read_to_string(logo_path)?
+        // Try to load from resources directory first
+        let resource_path = Path::new("resources").join("fumofetch_logo.txt");
+
+        if resource_path.exists() {
+            fs::read_to_string(resource_path)?
+        } else {
+            // Fallback to checking in current directory
+            let logo_path = Path::new("fumofetch_logo.txt");
+            fs::read_to_string(logo_path)?
+        }
     };
     
     // Process the logo content line by line, preserving all ANSI escape sequences